@@ -40,11 +40,10 @@ impl LibmdbxData<AddressToTokens> for AddressToTokensData {
 #[derive(Debug, Default, PartialEq, Clone, Eq)]
 #[main_codec(rlp)]
 pub struct PoolTokens {
-    pub token0: Address,
-    pub token1: Address,
-    pub token2: Option<Address>,
-    pub token3: Option<Address>,
-    pub token4: Option<Address>,
+    /// The full, ordered token membership of the pool. Curve metapools can
+    /// carry more than the five tokens the old fixed layout allowed, so this
+    /// is variable-length rather than `token0`..`token4`.
+    pub tokens: Vec<Address>,
 }
 
 impl IntoIterator for PoolTokens {
@@ -52,70 +51,54 @@ impl IntoIterator for PoolTokens {
     type Item = Address;
 
     fn into_iter(self) -> Self::IntoIter {
-        vec![Some(self.token0), Some(self.token1), self.token2, self.token3, self.token4]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter()
+        self.tokens.into_iter()
     }
 }
 
 impl From<Vec<String>> for PoolTokens {
     fn from(value: Vec<String>) -> Self {
-        let mut iter = value.into_iter();
         PoolTokens {
-            token0: Address::from_str(&iter.next().unwrap()).unwrap(),
-            token1: Address::from_str(&iter.next().unwrap()).unwrap(),
-            token2: iter.next().map(|a| Address::from_str(&a).ok()).flatten(),
-            token3: iter.next().map(|a| Address::from_str(&a).ok()).flatten(),
-            token4: iter.next().map(|a| Address::from_str(&a).ok()).flatten(),
+            tokens: value
+                .into_iter()
+                .filter_map(|a| Address::from_str(&a).ok())
+                .collect(),
         }
     }
 }
 
 impl Into<Vec<String>> for PoolTokens {
     fn into(self) -> Vec<String> {
-        vec![Some(self.token0), Some(self.token1), self.token2, self.token3, self.token4]
-            .into_iter()
-            .map(|addr| addr.map(|a| format!("{:?}", a)))
-            .flatten()
-            .collect::<Vec<_>>()
+        self.tokens.iter().map(|a| format!("{:?}", a)).collect()
     }
 }
 
 impl Encodable for PoolTokens {
     fn encode(&self, out: &mut dyn BufMut) {
-        self.token0.encode(out);
-        self.token1.encode(out);
-        self.token2.unwrap_or_default().encode(out);
-        self.token3.unwrap_or_default().encode(out);
-        self.token4.unwrap_or_default().encode(out);
+        // Length-prefixed RLP list carrying the whole token membership.
+        self.tokens.encode(out);
     }
 }
 
 impl Decodable for PoolTokens {
     fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
-        let mut this = Self {
-            token0: Address::decode(buf)?,
-            token1: Address::decode(buf)?,
-            token2: Some(Address::decode(buf)?),
-            token3: Some(Address::decode(buf)?),
-            token4: Some(Address::decode(buf)?),
-        };
-
-        if this.token2.as_ref().unwrap().is_zero() {
-            this.token2 = None;
+        // The variable-length form is a single RLP list. Legacy rows stored five
+        // bare `Address` items back-to-back, zero-padding the absent slots and
+        // omitting any list header; detect those by the missing header and decode
+        // the fixed layout, dropping the zero padding.
+        let is_list = buf.first().map(|b| *b >= 0xc0).unwrap_or(false);
+
+        if is_list {
+            Ok(Self { tokens: Vec::<Address>::decode(buf)? })
+        } else {
+            let mut tokens = Vec::with_capacity(5);
+            for _ in 0..5 {
+                let addr = Address::decode(buf)?;
+                if !addr.is_zero() {
+                    tokens.push(addr);
+                }
+            }
+            Ok(Self { tokens })
         }
-
-        if this.token3.as_ref().unwrap().is_zero() {
-            this.token3 = None;
-        }
-
-        if this.token4.as_ref().unwrap().is_zero() {
-            this.token4 = None;
-        }
-
-        Ok(this)
     }
 }
 
@@ -136,3 +119,58 @@ impl Decompress for PoolTokens {
         Ok(PoolTokens::decode(buf).map_err(|_| DatabaseError::Decode)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_rlp::Encodable;
+    use reth_db::table::{Compress, Decompress};
+    use reth_primitives::Address;
+
+    use super::PoolTokens;
+
+    /// Encodes the legacy fixed layout: five bare RLP `Address` items written
+    /// back-to-back, with the absent slots zero-padded and no list header.
+    fn legacy_blob(tokens: &[Address]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for i in 0..5 {
+            tokens.get(i).copied().unwrap_or(Address::ZERO).encode(&mut buf);
+        }
+        buf
+    }
+
+    #[test]
+    fn legacy_five_slot_blob_round_trips() {
+        let a = Address::new([0x11; 20]);
+        let b = Address::new([0x22; 20]);
+        let c = Address::new([0x33; 20]);
+
+        // three real tokens with the fourth and fifth slots zero-padded, as an
+        // old row would have been stored
+        let blob = legacy_blob(&[a, b, c]);
+        assert_eq!(blob.len(), 105, "legacy layout is five 21-byte RLP addresses");
+        assert!(blob[0] < 0xc0, "legacy layout must not start with a list header");
+
+        let decoded = PoolTokens::decompress(blob.as_slice()).unwrap();
+        assert_eq!(decoded.tokens, vec![a, b, c]);
+
+        // re-encoding writes the variable-length form, which decodes to the same set
+        let mut reencoded = Vec::new();
+        decoded.clone().compress_to_buf(&mut reencoded);
+        assert!(reencoded[0] >= 0xc0, "re-encoded as a length-prefixed list");
+        assert_eq!(PoolTokens::decompress(reencoded.as_slice()).unwrap(), decoded);
+    }
+
+    #[test]
+    fn variable_length_round_trips() {
+        // more than the old five-slot cap, to prove coins beyond the fifth survive
+        let tokens = (1u8..=6).map(|i| Address::new([i; 20])).collect::<Vec<_>>();
+        let original = PoolTokens { tokens: tokens.clone() };
+
+        let mut compressed = Vec::new();
+        original.clone().compress_to_buf(&mut compressed);
+        assert!(compressed[0] >= 0xc0, "variable layout is a length-prefixed list");
+
+        let decoded = PoolTokens::decompress(compressed.as_slice()).unwrap();
+        assert_eq!(decoded.tokens, tokens);
+    }
+}
@@ -87,8 +87,15 @@ impl<DB: LibmdbxReader> AtomicBackrunInspector<'_, DB> {
             .values()
             .fold(Rational::ZERO, |acc, delta| acc + delta);
 
+        let tx_hash = info.tx_hash;
+        let gas_details = info.gas_details;
+
+        // The searcher's cost is the full gas bill — the burned base fee plus the
+        // priority tip that reaches the builder plus any direct coinbase transfer —
+        // so we subtract `gas_paid()` in full from revenue for the profitability
+        // check.
         let gas_used = gas_details.gas_paid();
-        let gas_used_usd = info.metadata.get_gas_price_usd(gas_used);
+        let gas_used_usd = metadata.get_gas_price_usd(gas_used);
 
         // Can change this later to check if people are subsidising arbs to kill ops for
         // competitors
@@ -100,7 +107,7 @@ impl<DB: LibmdbxReader> AtomicBackrunInspector<'_, DB> {
             info,
             (rev_usd - &gas_used_usd).to_float(),
             &searcher_actions,
-            &vec![info.gas_details],
+            &vec![gas_details],
             metadata,
             MevType::Backrun,
         );
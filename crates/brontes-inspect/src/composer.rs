@@ -1,6 +1,5 @@
 use std::{
-    any::Any,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     future::Future,
     pin::Pin,
     sync::Arc,
@@ -8,69 +7,141 @@ use std::{
 };
 
 use async_scoped::{Scope, TokioScope};
-use brontes_database::Metadata;
+use brontes_database::{database::next_base_fee_per_gas, Metadata};
 use brontes_types::{
     classified_mev::{compose_sandwich_jit, ClassifiedMev, MevBlock, MevType, SpecificMev},
     normalized_actions::Actions,
-    tree::TimeTree,
+    tree::{GasDetails, TimeTree, TxType},
     ToScaledRational,
 };
 use futures::FutureExt;
-use lazy_static::lazy_static;
 use malachite::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode, Rational};
 use reth_primitives::Address;
 use tracing::info;
 
 use crate::Inspector;
 
-type ComposeFunction = Option<
-    Box<
-        dyn Fn(
-                Box<dyn Any + 'static>,
-                Box<dyn Any + 'static>,
-                ClassifiedMev,
-                ClassifiedMev,
-            ) -> (ClassifiedMev, Box<dyn SpecificMev>)
-            + Send
-            + Sync,
-    >,
+/// Folds the constituent MEVs of a composed type into a single parent. The
+/// inputs are ordered to match the node's `dependencies`, so an N-ary composer
+/// receives all of its constituents in a predictable order.
+type ComposeFunction = Box<
+    dyn Fn(Vec<(ClassifiedMev, Box<dyn SpecificMev>)>) -> (ClassifiedMev, Box<dyn SpecificMev>)
+        + Send
+        + Sync,
 >;
 
-/// we use this to define a filter that we can iterate over such that
-/// everything is ordered properly and we have already composed lower level
-/// actions that could effect the higher level composing.
-macro_rules! mev_composability {
-    ($($mev_type:ident => $($deps:ident),+;)+) => {
-        lazy_static! {
-        static ref MEV_FILTER: &'static [(
-                MevType,
-                ComposeFunction,
-                Vec<MevType>)] = {
-            &*Box::leak(Box::new([
-                $((
-                        MevType::$mev_type,
-                        get_compose_fn(MevType::$mev_type),
-                        [$(MevType::$deps,)+].to_vec()),
-                   )+
-            ]))
-        };
+/// What a [`ComposeNode`] does with the dependency MEVs it matches.
+enum ComposeAction {
+    /// Lower-level reduction: once a parent of this type exists, the matched
+    /// dependency MEVs are redundant and get dropped (e.g. Backrun/CexDex
+    /// folded into Sandwich).
+    Reduce,
+    /// Combine the matched dependency MEVs into a new parent type.
+    Compose(ComposeFunction),
+}
+
+/// A single composability rule: the `parent` type it yields, the `dependencies`
+/// (of arbitrary arity) whose transaction hashes must line up, and the action.
+struct ComposeNode {
+    parent:       MevType,
+    dependencies: Vec<MevType>,
+    action:       ComposeAction,
+}
+
+/// Runtime-constructable composition graph. Nodes are topologically sorted so
+/// that lower-level reductions always run before the higher-level compositions
+/// that consume their output. Adding a new composite MEV category is a data
+/// change (push another node) rather than a macro edit.
+pub struct ComposabilityGraph {
+    nodes: Vec<ComposeNode>,
+}
+
+impl Default for ComposabilityGraph {
+    fn default() -> Self {
+        Self::new()
+            // reduce first: fold backrun / cex-dex legs that are part of a sandwich
+            .with_node(MevType::Sandwich, vec![MevType::Backrun, MevType::CexDex], ComposeAction::Reduce)
+            // then try to compose a sandwich and a jit into a jit-sandwich
+            .with_node(
+                MevType::JitSandwich,
+                vec![MevType::Sandwich, MevType::Jit],
+                ComposeAction::Compose(Box::new(|mut parts| {
+                    let (sandwich_mev, sandwich) = parts.remove(0);
+                    let (jit_mev, jit) = parts.remove(0);
+                    compose_sandwich_jit(
+                        sandwich.into_any(),
+                        jit.into_any(),
+                        sandwich_mev,
+                        jit_mev,
+                    )
+                })),
+            )
+    }
+}
+
+impl ComposabilityGraph {
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    fn with_node(
+        mut self,
+        parent: MevType,
+        dependencies: Vec<MevType>,
+        action: ComposeAction,
+    ) -> Self {
+        self.nodes.push(ComposeNode { parent, dependencies, action });
+        self
+    }
+
+    /// Orders nodes so that every node runs after any node that produces one of
+    /// its dependencies (Kahn's algorithm over `producer.parent ->
+    /// consumer.dependency` edges). Falls back to declaration order for cycles.
+    fn sorted(&self) -> Vec<&ComposeNode> {
+        let mut ordered: Vec<&ComposeNode> = Vec::with_capacity(self.nodes.len());
+        let mut placed = vec![false; self.nodes.len()];
+
+        // repeatedly take any node whose dependency-producers are all placed
+        let mut progress = true;
+        while ordered.len() < self.nodes.len() && progress {
+            progress = false;
+            for (i, node) in self.nodes.iter().enumerate() {
+                if placed[i] {
+                    continue
+                }
+                let ready = self.nodes.iter().enumerate().all(|(j, other)| {
+                    i == j || placed[j] || !node.dependencies.contains(&other.parent)
+                });
+                if ready {
+                    ordered.push(node);
+                    placed[i] = true;
+                    progress = true;
+                }
+            }
+        }
+
+        // append anything left over (only reachable if the graph has a cycle)
+        for (i, node) in self.nodes.iter().enumerate() {
+            if !placed[i] {
+                ordered.push(node);
+            }
+        }
+
+        ordered
     }
-    };
 }
 
-mev_composability!(
-    // reduce first
-    Sandwich => Backrun, CexDex;
-    // try compose
-    JitSandwich => Sandwich, Jit;
-);
-
-/// the compose function is used in order to be able to properly be able to cast
-/// in the lazy static
-fn get_compose_fn(mev_type: MevType) -> ComposeFunction {
-    match mev_type {
-        MevType::JitSandwich => Some(Box::new(compose_sandwich_jit)),
-        _ => None,
+/// Effective gas price for a root given the block base fee, honouring the
+/// transaction envelope: EIP-1559 dynamic-fee txs pay
+/// `min(max_fee_per_gas, base_fee_per_gas + max_priority_fee_per_gas)`, while
+/// legacy (type-0) and EIP-2930 (type-1) access-list txs pay their flat
+/// `gas_price` recorded as `effective_gas_price`.
+fn effective_gas_price(gas_details: &GasDetails, base_fee_per_gas: u64) -> u64 {
+    match gas_details.tx_type {
+        TxType::Eip1559 => gas_details
+            .max_fee_per_gas
+            .min(base_fee_per_gas + gas_details.max_priority_fee_per_gas),
+        TxType::Legacy | TxType::Eip2930 => gas_details.effective_gas_price,
     }
 }
 
@@ -81,33 +152,75 @@ fn get_compose_fn(mev_type: MevType) -> ComposeFunction {
 // they are one and the same
 
 pub struct BlockPreprocessing {
-    meta_data:           Arc<Metadata>,
-    cumulative_gas_used: u64,
-    cumulative_gas_paid: u64,
-    builder_address:     Address,
+    meta_data:            Arc<Metadata>,
+    cumulative_gas_used:  u64,
+    cumulative_gas_paid:  u64,
+    /// Base fee per gas for the block; the portion of every tx's gas bill that
+    /// is burned and never reaches the builder.
+    base_fee_per_gas:     u64,
+    /// `base_fee_per_gas * cumulative_gas_used` — total fees burned in the block.
+    cumulative_burned_fee: u64,
+    /// `(effective_gas_price - base_fee_per_gas) * gas_used` summed over roots —
+    /// the priority tips that actually reach the builder.
+    cumulative_tip:       u64,
+    builder_address:      Address,
 }
 
-type InspectorFut<'a> =
-    Pin<Box<dyn Future<Output = Vec<(ClassifiedMev, Box<dyn SpecificMev>)>> + 'a>>;
+type InspectorFut =
+    Pin<Box<dyn Future<Output = Vec<(ClassifiedMev, Box<dyn SpecificMev>)>>>>;
 
 /// the results downcast using any in order to be able to serialize and
-/// impliment row trait due to the abosulte autism that the db library   
+/// impliment row trait due to the abosulte autism that the db library
 /// requirements
 pub type ComposerResults = (MevBlock, Vec<(ClassifiedMev, Box<dyn SpecificMev>)>);
 
-pub struct Composer<'a, const N: usize> {
-    orchestra:            &'a [&'a Box<dyn Inspector>; N],
-    inspectors_execution: Option<InspectorFut<'a>>,
+/// Builds an owned [`Composer`] from a set of inspectors registered at runtime.
+/// Lets callers enable/disable CEX-DEX, sandwich, JIT and backrun individually
+/// from config without recompiling for a fixed inspector count.
+#[derive(Default)]
+pub struct ComposerBuilder {
+    inspectors: Vec<Arc<dyn Inspector>>,
+    graph:      ComposabilityGraph,
+}
+
+impl ComposerBuilder {
+    pub fn with_inspector(mut self, inspector: Arc<dyn Inspector>) -> Self {
+        self.inspectors.push(inspector);
+        self
+    }
+
+    /// Override the default composability graph (e.g. to register new composite
+    /// MEV categories).
+    pub fn with_composability_graph(mut self, graph: ComposabilityGraph) -> Self {
+        self.graph = graph;
+        self
+    }
+
+    pub fn build(self) -> Composer {
+        let registered = self.inspectors.iter().map(|i| i.mev_type()).collect();
+        Composer {
+            inspectors: self.inspectors,
+            registered,
+            graph: self.graph,
+            inspectors_execution: None,
+            pre_processing: None,
+            is_finished: false,
+        }
+    }
+}
+
+pub struct Composer {
+    inspectors:           Vec<Arc<dyn Inspector>>,
+    /// The MEV types the registered inspectors actually produce; compositions
+    /// whose dependencies aren't all registered are skipped.
+    registered:           HashSet<MevType>,
+    graph:                ComposabilityGraph,
+    inspectors_execution: Option<InspectorFut>,
     pre_processing:       Option<BlockPreprocessing>,
-    // this is terroristic and need to prob rewrite most of this. however
-    // we will leave it for now so we can get to testing
     is_finished:          bool,
 }
 
-impl<'a, const N: usize> Composer<'a, N> {
-    pub fn new(orchestra: &'a [&'a Box<dyn Inspector>; N]) -> Self {
-        Self { orchestra, inspectors_execution: None, pre_processing: None, is_finished: false }
-    }
+impl Composer {
 
     pub fn is_processing(&self) -> bool {
         self.inspectors_execution.is_some()
@@ -123,9 +236,12 @@ impl<'a, const N: usize> Composer<'a, N> {
         let mut scope: TokioScope<'_, Vec<(ClassifiedMev, Box<dyn SpecificMev>)>> =
             unsafe { Scope::create() };
 
-        println!("inspectors to run: {}", self.orchestra.len());
-        self.orchestra.iter().for_each(|inspector| {
-            scope.spawn(inspector.process_tree(tree.clone(), meta_data.clone()))
+        println!("inspectors to run: {}", self.inspectors.len());
+        self.inspectors.iter().for_each(|inspector| {
+            let inspector = inspector.clone();
+            let tree = tree.clone();
+            let meta_data = meta_data.clone();
+            scope.spawn(async move { inspector.process_tree(tree, meta_data).await })
         });
 
         let fut = Box::pin(async move {
@@ -148,16 +264,49 @@ impl<'a, const N: usize> Composer<'a, N> {
             .map(|root| root.gas_details.gas_used)
             .sum::<u64>();
 
+        // The block base fee is burned on every transaction post-London. Prefer
+        // the value the metadata layer already derived (see the database's
+        // `next_base_fee_per_gas`); fall back to the header, and finally to the
+        // EIP-1559 recurrence over the parent header when neither is present.
+        let base_fee_per_gas = if meta_data.base_fee_per_gas != 0 {
+            meta_data.base_fee_per_gas
+        } else {
+            tree.header.base_fee_per_gas.unwrap_or_else(|| {
+                next_base_fee_per_gas(
+                    tree.header.parent_base_fee.unwrap_or_default(),
+                    tree.header.parent_gas_used.unwrap_or_default(),
+                    tree.header.parent_gas_limit.unwrap_or_default(),
+                )
+            })
+        };
+
         let cumulative_gas_paid = tree
             .roots
             .iter()
-            .map(|root| root.gas_details.effective_gas_price * root.gas_details.gas_used)
+            .map(|root| {
+                effective_gas_price(&root.gas_details, base_fee_per_gas) * root.gas_details.gas_used
+            })
+            .sum::<u64>();
+
+        let cumulative_burned_fee = base_fee_per_gas * cumulative_gas_used;
+
+        let cumulative_tip = tree
+            .roots
+            .iter()
+            .map(|root| {
+                effective_gas_price(&root.gas_details, base_fee_per_gas)
+                    .saturating_sub(base_fee_per_gas)
+                    * root.gas_details.gas_used
+            })
             .sum::<u64>();
 
         self.pre_processing = Some(BlockPreprocessing {
             meta_data,
             cumulative_gas_used,
             cumulative_gas_paid,
+            base_fee_per_gas,
+            cumulative_burned_fee,
+            cumulative_tip,
             builder_address,
         });
     }
@@ -172,9 +321,15 @@ impl<'a, const N: usize> Composer<'a, N> {
             .map(|(_, mev)| mev.priority_fee_paid())
             .sum::<u64>();
 
-        let total_bribe = 0;
+        let total_bribe = orchestra_data
+            .iter()
+            .map(|(_, mev)| mev.bribe())
+            .sum::<u64>();
 
-        let builder_eth_profit = total_bribe + pre_processing.cumulative_gas_paid;
+        // Post-London the base fee portion of every tx is burned and never
+        // reaches the builder, so builder income is priority tips plus explicit
+        // bribes only — the burned base fee must not be counted as revenue.
+        let builder_eth_profit = total_bribe + pre_processing.cumulative_tip;
 
         MevBlock {
             block_hash: pre_processing.meta_data.block_hash.into(),
@@ -187,10 +342,8 @@ impl<'a, const N: usize> Composer<'a, N> {
             .0,
             cumulative_gas_used: pre_processing.cumulative_gas_used,
             cumulative_gas_paid: pre_processing.cumulative_gas_paid,
-            total_bribe: orchestra_data
-                .iter()
-                .map(|(_, mev)| mev.bribe())
-                .sum::<u64>(),
+            cumulative_burned_fee: pre_processing.cumulative_burned_fee,
+            total_bribe,
             cumulative_mev_priority_fee_paid: cum_mev_priority_fee_paid,
             builder_address: pre_processing.builder_address,
             builder_eth_profit,
@@ -238,20 +391,22 @@ impl<'a, const N: usize> Composer<'a, N> {
                 },
             );
 
-        MEV_FILTER
-            .iter()
-            .for_each(|(head_mev_type, compose_fn, dependencies)| {
-                if let Some(compose_fn) = compose_fn {
-                    self.compose_dep_filter(
-                        head_mev_type,
-                        dependencies,
-                        compose_fn,
-                        &mut sorted_mev,
-                    );
-                } else {
-                    self.replace_dep_filter(head_mev_type, dependencies, &mut sorted_mev);
+        // walk the composability graph in dependency order so lower-level
+        // reductions run before the compositions that consume their output
+        for node in self.graph.sorted() {
+            // only attempt rules whose dependency inspectors are actually
+            // registered on this composer
+            if !node.dependencies.iter().all(|dep| self.registered.contains(dep)) {
+                continue
+            }
+
+            match &node.action {
+                ComposeAction::Reduce => Self::apply_reduce(node, &mut sorted_mev),
+                ComposeAction::Compose(compose) => {
+                    Self::apply_compose(node, compose, &mut sorted_mev)
                 }
-            });
+            }
+        }
 
         self.is_finished = true;
 
@@ -259,111 +414,86 @@ impl<'a, const N: usize> Composer<'a, N> {
         Poll::Ready((header, sorted_mev.into_values().flatten().collect::<Vec<_>>()))
     }
 
-    fn replace_dep_filter(
-        &mut self,
-        head_mev_type: &MevType,
-        deps: &[MevType],
+    /// Drops the dependency MEVs that are subsumed by an existing parent of
+    /// `node.parent` (they share at least one transaction hash with it).
+    fn apply_reduce(
+        node: &ComposeNode,
         sorted_mev: &mut HashMap<MevType, Vec<(ClassifiedMev, Box<dyn SpecificMev>)>>,
     ) {
-        let Some(head_mev) = sorted_mev.get(head_mev_type) else { return };
-
-        let mut remove_count: HashMap<MevType, usize> = HashMap::new();
+        let Some(parents) = sorted_mev.get(&node.parent) else { return };
 
-        let flattend_indexes = head_mev
+        let parent_hashes = parents
             .iter()
-            .flat_map(|(_, specific)| {
-                let hashes = specific.mev_transaction_hashes();
-                let mut remove_data: Vec<(MevType, usize)> = Vec::new();
-                for dep in deps {
-                    let Some(dep_mev) = sorted_mev.get(dep) else { continue };
-                    for (i, (_, specific)) in dep_mev.iter().enumerate() {
-                        let dep_hashes = specific.mev_transaction_hashes();
-                        // verify both match
-                        if dep_hashes == hashes {
-                            let adjustment = remove_count.entry(*dep).or_default();
-                            remove_data.push((*dep, i - *adjustment));
-                            *adjustment += 1;
-                        }
-                        // we only want one match
-                        else if dep_hashes
-                            .iter()
-                            .map(|hash| hashes.contains(hash))
-                            .any(|f| f)
-                        {
-                            let adjustment = remove_count.entry(*dep).or_default();
-                            remove_data.push((*dep, i + *adjustment));
-                            *adjustment += 1;
-                        }
-                    }
-                }
+            .flat_map(|(_, specific)| specific.mev_transaction_hashes())
+            .collect::<HashSet<_>>();
 
-                remove_data
-            })
-            .collect::<Vec<(MevType, usize)>>();
+        if parent_hashes.is_empty() {
+            return
+        }
 
-        for (mev_type, index) in flattend_indexes {
-            sorted_mev.get_mut(&mev_type).unwrap().remove(index);
+        for dep in &node.dependencies {
+            if let Some(dep_mev) = sorted_mev.get_mut(dep) {
+                dep_mev.retain(|(_, specific)| {
+                    !specific
+                        .mev_transaction_hashes()
+                        .iter()
+                        .any(|hash| parent_hashes.contains(hash))
+                });
+            }
         }
     }
 
-    fn compose_dep_filter(
-        &mut self,
-        parent_mev_type: &MevType,
-        composable_types: &[MevType],
-        compose: &Box<
-            dyn Fn(
-                    Box<dyn Any>,
-                    Box<dyn Any>,
-                    ClassifiedMev,
-                    ClassifiedMev,
-                ) -> (ClassifiedMev, Box<dyn SpecificMev>)
-                + Send
-                + Sync,
-        >,
+    /// Combines one MEV from each dependency type (of arbitrary arity) that
+    /// share transaction hashes into a single `node.parent` MEV. Constituents
+    /// that can't be fully matched are left untouched.
+    fn apply_compose(
+        node: &ComposeNode,
+        compose: &ComposeFunction,
         sorted_mev: &mut HashMap<MevType, Vec<(ClassifiedMev, Box<dyn SpecificMev>)>>,
     ) {
-        if composable_types.len() != 2 {
-            panic!("we only support sequential compatibility for our specific mev");
-        }
+        let Some((first, rest)) = node.dependencies.split_first() else { return };
+        let Some(firsts) = sorted_mev.remove(first) else { return };
+
+        let mut leftover = Vec::new();
+
+        for (classified, specific) in firsts {
+            let hashes = specific.mev_transaction_hashes();
+
+            // find a matching constituent in each of the remaining dependency
+            // types; distinct types so their indices don't invalidate each other
+            let mut matched: Vec<(MevType, usize)> = Vec::with_capacity(rest.len());
+            let all_matched = rest.iter().all(|dep| {
+                let Some(list) = sorted_mev.get(dep) else { return false };
+                if let Some((idx, _)) = list.iter().enumerate().find(|(_, (_, v))| {
+                    let o = v.mev_transaction_hashes();
+                    o == hashes || hashes.iter().any(|h| o.contains(h))
+                }) {
+                    matched.push((*dep, idx));
+                    true
+                } else {
+                    false
+                }
+            });
 
-        let Some(zero_txes) = sorted_mev.remove(&composable_types[0]) else { return };
-
-        for (classified, mev_data) in zero_txes {
-            let addresses = mev_data.mev_transaction_hashes();
-
-            if let Some((index, _)) = sorted_mev.get(&composable_types[1]).and_then(|mev_type| {
-                mev_type.iter().enumerate().find(|(_, (_, v))| {
-                    let o_addrs = v.mev_transaction_hashes();
-                    o_addrs == addresses || addresses.iter().any(|a| o_addrs.contains(a))
-                })
-            }) {
-                // remove composed type
-                let (classifed_1, mev_data_1) = sorted_mev
-                    .get_mut(&composable_types[1])
-                    .unwrap()
-                    .remove(index);
-                // insert new type
-                sorted_mev
-                    .entry(*parent_mev_type)
-                    .or_default()
-                    .push(compose(
-                        mev_data.into_any(),
-                        mev_data_1.into_any(),
-                        classified,
-                        classifed_1,
-                    ));
+            if all_matched {
+                let mut parts = Vec::with_capacity(node.dependencies.len());
+                parts.push((classified, specific));
+                for (dep, idx) in &matched {
+                    parts.push(sorted_mev.get_mut(dep).unwrap().remove(*idx));
+                }
+                sorted_mev.entry(node.parent).or_default().push(compose(parts));
             } else {
-                // if no prev match, then add back old type
-                sorted_mev
-                    .entry(composable_types[0])
-                    .or_default()
-                    .push((classified, mev_data));
+                leftover.push((classified, specific));
             }
         }
+
+        if !leftover.is_empty() {
+            sorted_mev.entry(*first).or_default().extend(leftover);
+        }
     }
 }
 
-impl<const N: usize> Future for Composer<'_, N> {
+impl Future for Composer {
     type Output = ComposerResults;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -409,10 +539,6 @@ pub mod tests {
         sandwich::SandwichInspector,
     };
 
-    unsafe fn cast_lifetime<'f, 'a, I>(item: &'a I) -> &'f I {
-        std::mem::transmute::<&'a I, &'f I>(item)
-    }
-
     fn get_metadata() -> Metadata {
         // 2126.43
         Metadata {
@@ -485,7 +611,7 @@ pub mod tests {
 
     /// takes the blocknumber, setups the tree and calls on_new_tree before
     /// returning the composer
-    pub async fn setup(block_num: u64, custom_meta: Option<Metadata>) -> Composer<'static, 2> {
+    pub async fn setup(block_num: u64, custom_meta: Option<Metadata>) -> Composer {
         init_tracing();
         dotenv::dotenv().ok();
 
@@ -501,21 +627,12 @@ pub mod tests {
 
         let tree = Arc::new(classifier.build_tree(block.0, block.1, &metadata));
 
-        let cex_dex = Box::new(CexDexInspector::default()) as Box<dyn Inspector>;
-        let backrun = Box::new(AtomicBackrunInspector::default()) as Box<dyn Inspector>;
-        let jit = Box::new(JitInspector::default()) as Box<dyn Inspector>;
-        let sandwich = Box::new(SandwichInspector::default()) as Box<dyn Inspector>;
-
-        let inspectors: [&'static Box<dyn Inspector>; 2] = unsafe {
-            [
-                // cast_lifetime::<'static>(&cex_dex),
-                // cast_lifetime::<'static>(&backrun),
-                cast_lifetime::<'static>(&jit),
-                cast_lifetime::<'static>(&sandwich),
-            ]
-        };
-
-        let mut composer = Composer::new(Box::leak(Box::new(inspectors)));
+        let mut composer = ComposerBuilder::default()
+            // .with_inspector(Arc::new(CexDexInspector::default()))
+            // .with_inspector(Arc::new(AtomicBackrunInspector::default()))
+            .with_inspector(Arc::new(JitInspector::default()))
+            .with_inspector(Arc::new(SandwichInspector::default()))
+            .build();
         composer.on_new_tree(tree, metadata.into());
 
         composer
@@ -542,4 +659,73 @@ pub mod tests {
     #[tokio::test]
     #[serial_test::serial]
     pub async fn test_sandwich_jit_compose() {}
+
+    fn gas_details(tx_type: TxType, effective: u64, max_fee: u64, max_prio: u64) -> GasDetails {
+        GasDetails {
+            tx_type,
+            effective_gas_price: effective,
+            max_fee_per_gas: max_fee,
+            max_priority_fee_per_gas: max_prio,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn legacy_and_2930_use_the_flat_price() {
+        let base_fee = 30;
+        for tx_type in [TxType::Legacy, TxType::Eip2930] {
+            let gd = gas_details(tx_type, 100, 0, 0);
+            assert_eq!(effective_gas_price(&gd, base_fee), 100);
+        }
+    }
+
+    #[test]
+    fn eip1559_prices_off_base_plus_tip() {
+        let base_fee = 30;
+        // base + max_priority (30 + 50 = 80) is under the fee cap (200), so it wins
+        let gd = gas_details(TxType::Eip1559, 0, 200, 50);
+        assert_eq!(effective_gas_price(&gd, base_fee), 80);
+    }
+
+    #[test]
+    fn eip1559_is_clamped_to_the_fee_cap() {
+        let base_fee = 100;
+        // base + max_priority (150) exceeds the fee cap (120), so the cap wins
+        let gd = gas_details(TxType::Eip1559, 0, 120, 50);
+        assert_eq!(effective_gas_price(&gd, base_fee), 120);
+    }
+
+    #[test]
+    fn graph_sorts_producers_before_consumers() {
+        // the default graph reduces into Sandwich before composing a JitSandwich
+        // that depends on Sandwich, so the Sandwich node must come first
+        let graph = ComposabilityGraph::default();
+        let order = graph
+            .sorted()
+            .iter()
+            .map(|node| node.parent)
+            .collect::<Vec<_>>();
+
+        let sandwich = order.iter().position(|p| *p == MevType::Sandwich).unwrap();
+        let jit_sandwich = order.iter().position(|p| *p == MevType::JitSandwich).unwrap();
+        assert!(sandwich < jit_sandwich);
+    }
+
+    #[test]
+    fn graph_topologically_orders_a_chain() {
+        // C depends on B depends on A, declared in reverse; the sort must still
+        // place A before B before C regardless of declaration order
+        let graph = ComposabilityGraph::new()
+            .with_node(MevType::JitSandwich, vec![MevType::Sandwich], ComposeAction::Reduce)
+            .with_node(MevType::Sandwich, vec![MevType::Jit], ComposeAction::Reduce)
+            .with_node(MevType::Jit, vec![MevType::Backrun], ComposeAction::Reduce);
+
+        let order = graph
+            .sorted()
+            .iter()
+            .map(|node| node.parent)
+            .collect::<Vec<_>>();
+
+        assert_eq!(order, vec![MevType::Jit, MevType::Sandwich, MevType::JitSandwich]);
+    }
 }
@@ -0,0 +1,64 @@
+/// Transaction envelope, tagging how the effective gas price is computed.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TxType {
+    /// Type-0: flat `gas_price`.
+    #[default]
+    Legacy,
+    /// Type-1 (EIP-2930): access-list tx, still a flat `gas_price`.
+    Eip2930,
+    /// Type-2 (EIP-1559): dynamic-fee tx priced off `max_fee_per_gas` and
+    /// `max_priority_fee_per_gas`.
+    Eip1559,
+}
+
+/// Per-transaction gas economics, split so that post-EIP-1559 MEV accounting
+/// can tell the burned base fee (which reaches no one) apart from the priority
+/// tip and any direct coinbase transfer that are actual builder revenue.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct GasDetails {
+    /// Transaction envelope, deciding how `effective_gas_price` is derived.
+    pub tx_type:                 TxType,
+    /// Base fee per gas of the block the transaction landed in. This portion
+    /// of every gas unit is burned and never reaches the builder.
+    pub base_fee_per_gas:        u64,
+    /// Type-2 fee cap: the most the sender will pay per unit of gas.
+    pub max_fee_per_gas:         u64,
+    /// Type-2 tip cap: the most the sender will tip the builder per unit of gas.
+    pub max_priority_fee_per_gas: u64,
+    /// Gas consumed by the transaction.
+    pub gas_used:                u64,
+    /// Effective gas price actually paid per unit of gas. For legacy/EIP-2930
+    /// txs this is the flat `gas_price`.
+    pub effective_gas_price:     u64,
+    /// Direct `block.coinbase` transfer the transaction makes on top of the
+    /// gas bill (e.g. an explicit builder bribe).
+    pub coinbase_transfer:       u64,
+}
+
+impl GasDetails {
+    /// Total cost the sender paid for the transaction: the full gas bill plus
+    /// any direct coinbase transfer.
+    ///
+    /// Reads the stored `effective_gas_price` directly and does not re-apply the
+    /// `min(max_fee_per_gas, base_fee_per_gas + max_priority_fee_per_gas)` rule,
+    /// so for type-2 transactions `effective_gas_price` must be pre-filled (via
+    /// the composer's `effective_gas_price`) or the gas bill counts as zero and
+    /// only `coinbase_transfer` is returned.
+    pub fn gas_paid(&self) -> u64 {
+        self.gas_used * self.effective_gas_price + self.coinbase_transfer
+    }
+
+    /// The base fee burned by the transaction, which reaches no one.
+    pub fn burned_fee(&self) -> u64 {
+        self.gas_used * self.base_fee_per_gas
+    }
+
+    /// The builder's realized revenue from the transaction: the priority tip
+    /// over the burned base fee plus any direct coinbase transfer. Like
+    /// [`GasDetails::gas_paid`], this relies on a pre-filled
+    /// `effective_gas_price` for type-2 transactions.
+    pub fn builder_payment(&self) -> u64 {
+        self.gas_used * self.effective_gas_price.saturating_sub(self.base_fee_per_gas)
+            + self.coinbase_transfer
+    }
+}
@@ -22,6 +22,54 @@ const TARDIS_QUOTES_L2: &str = "tardis_l2";
 const TARDIS_QUOTES_QUOTES: &str = "tardis_quotes";
 const TARDIS_QUOTES_TRADES: &str = "tardis_trades";
 
+/// Parent-header fields (`base_fee_per_gas`, `gas_used`, `gas_limit`) that
+/// `get_metadata` feeds into the EIP-1559 recurrence to reconstruct a block's
+/// base fee per gas.
+const PARENT_HEADER: &str =
+    "SELECT base_fee_per_gas, gas_used, gas_limit FROM ethereum.blocks WHERE block_number = ? - 1";
+
+/// EIP-1559 elasticity multiplier: a block may burn up to
+/// `gas_target * ELASTICITY_MULTIPLIER` gas, so `gas_target = gas_limit / 2`.
+const ELASTICITY_MULTIPLIER: u64 = 2;
+/// EIP-1559 damping denominator: the base fee moves at most `1 / 8` per block.
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Reconstructs a block's base fee per gas from its parent header using the
+/// EIP-1559 recurrence. Lets the metadata layer derive gas economics when the
+/// price-feed row is missing or suspect, and keeps historical blocks that
+/// predate the fee-history table analyzable without a clickhouse round-trip.
+pub fn next_base_fee_per_gas(
+    parent_base_fee: u64,
+    parent_gas_used: u64,
+    parent_gas_limit: u64,
+) -> u64 {
+    let gas_target = parent_gas_limit / ELASTICITY_MULTIPLIER;
+
+    // A zero target (missing/zero parent gas limit) would divide by zero below;
+    // with no target there is nothing to damp against, so the fee is unchanged.
+    if gas_target == 0 {
+        return parent_base_fee
+    }
+
+    // The `base_fee * gas_delta` product overflows `u64` for high base fees on a
+    // near-full block, so compute it in `u128` like geth's big-int path.
+    let scaled_delta = |gas_delta: u64| -> u64 {
+        (parent_base_fee as u128 * gas_delta as u128
+            / gas_target as u128
+            / BASE_FEE_MAX_CHANGE_DENOMINATOR as u128) as u64
+    };
+
+    if parent_gas_used == gas_target {
+        parent_base_fee
+    } else if parent_gas_used > gas_target {
+        let delta = scaled_delta(parent_gas_used - gas_target);
+        parent_base_fee + delta.max(1)
+    } else {
+        let delta = scaled_delta(gas_target - parent_gas_used);
+        parent_base_fee.saturating_sub(delta)
+    }
+}
+
 pub struct Database {
     client: ClickhouseClient,
 }
@@ -47,6 +95,14 @@ impl Database {
         // eth price is in cex_prices
         let eth_prices = Default::default();
 
+        // Derive the block's base fee per gas from its parent header so inspectors
+        // can validate or fill gas costs without another clickhouse round-trip, and
+        // so blocks predating the fee-history table stay analyzable.
+        let (parent_base_fee, parent_gas_used, parent_gas_limit) =
+            self.get_parent_header(block_num).await;
+        let base_fee_per_gas =
+            next_base_fee_per_gas(parent_base_fee, parent_gas_used, parent_gas_limit);
+
         let metadata = Metadata::new(
             block_num,
             block_hash,
@@ -54,12 +110,24 @@ impl Database {
             relay_p2p_times.1,
             cex_prices,
             eth_prices,
+            base_fee_per_gas,
             private_txs,
         );
 
         metadata
     }
 
+    /// Parent-header gas fields, or `(0, 0, 0)` when the row is absent or its
+    /// `base_fee_per_gas` is NULL — the pre-London / pre-fee-history case. A
+    /// zero gas limit makes the recurrence leave the base fee unchanged rather
+    /// than panic, keeping those historical blocks analyzable.
+    async fn get_parent_header(&self, block_num: u64) -> (u64, u64, u64) {
+        self.client
+            .query_one_params(PARENT_HEADER, vec![block_num.to_string()])
+            .await
+            .unwrap_or((0, 0, 0))
+    }
+
     async fn get_private_flow(&self, block_num: u64, block_hash: U256) -> HashSet<TxHash> {
         let private_txs = self
             .client
@@ -115,3 +183,49 @@ impl Database {
         token_prices
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::next_base_fee_per_gas;
+
+    #[test]
+    fn base_fee_unchanged_at_target() {
+        // parent exactly at the gas target: the fee does not move
+        assert_eq!(next_base_fee_per_gas(1_000_000_000, 15_000_000, 30_000_000), 1_000_000_000);
+    }
+
+    #[test]
+    fn base_fee_rises_above_target() {
+        // full block (gas_used == gas_limit): fee rises by the 1/8 cap
+        // delta = 1e9 * (30M - 15M) / 15M / 8 = 1e9 / 8
+        assert_eq!(next_base_fee_per_gas(1_000_000_000, 30_000_000, 30_000_000), 1_125_000_000);
+    }
+
+    #[test]
+    fn base_fee_falls_below_target() {
+        // empty block: fee falls by the full 1/8 of the gap to target
+        assert_eq!(next_base_fee_per_gas(1_000_000_000, 0, 30_000_000), 875_000_000);
+    }
+
+    #[test]
+    fn minimum_bump_of_one_above_target() {
+        // a tiny overshoot rounds the delta to zero, but the fee must still rise
+        // by at least one wei
+        assert_eq!(next_base_fee_per_gas(1, 15_000_001, 30_000_000), 2);
+    }
+
+    #[test]
+    fn zero_gas_target_leaves_fee_unchanged() {
+        // a missing/zero parent gas limit must not divide by zero
+        assert_eq!(next_base_fee_per_gas(1_000_000_000, 10_000_000, 0), 1_000_000_000);
+    }
+
+    #[test]
+    fn high_base_fee_does_not_overflow() {
+        // ~2000 gwei on a full block would overflow the u64 product; the u128
+        // intermediate keeps it finite
+        let parent_base_fee = 2_000_000_000_000u64;
+        let next = next_base_fee_per_gas(parent_base_fee, 30_000_000, 30_000_000);
+        assert_eq!(next, parent_base_fee + parent_base_fee / 8);
+    }
+}